@@ -3,7 +3,8 @@ mod tui;
 mod metrics;
 
 use clap::{Parser, Subcommand};
-use relativity::special::lorentz_factor;
+use relativity::special::{lorentz_factor, length_contraction};
+use metrics::{DataPoint, PlotFormat, export_csv, plot_results, plot_results_console};
 use anyhow::Result;
 
 /// QSIS - Quantum Spacetime Intelligence System
@@ -12,6 +13,10 @@ use anyhow::Result;
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Increase log verbosity (-v info, -vv debug, -vvv trace)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
 }
 
 #[derive(Subcommand)]
@@ -19,42 +24,88 @@ enum Commands {
     /// Run interactive TUI simulation
     Tui,
     /// Generate metrics and export to CSV
-    Metrics,
+    Metrics {
+        /// Render the chart as ASCII art to stdout instead of a plot file (for headless/SSH use)
+        #[arg(long)]
+        ascii: bool,
+
+        /// Image format for the plot file, when not using --ascii
+        #[arg(long, value_enum, default_value_t = PlotFormat::Png)]
+        format: PlotFormat,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    let _tracing_guard = init_tracing(cli.verbose);
 
     match cli.command {
         Some(Commands::Tui) => tui::start()?,
-        Some(Commands::Metrics) => run_metrics()?,
+        Some(Commands::Metrics { ascii, format }) => run_metrics(ascii, format)?,
         None => tui::start()?, // default
     }
 
     Ok(())
 }
 
-fn run_metrics() -> anyhow::Result<()> {
-    // use std::fs::File;
-    // use std::io::Write;
-    // let mut file = File::create("metrics.csv")?;
-    // writeln!(file, "velocity_fraction,gamma,proper_time,dilated_time, proper_length, contracted_length")?;
+/// Initialize the `tracing` subscriber, when opted into with `-v`: a rolling
+/// daily file under `logs/` (stdout/stderr are left alone since the TUI draws
+/// over the whole terminal). At the default verbosity (0) no subscriber is
+/// installed and no `logs/` directory is created. The returned guard, if any,
+/// must be kept alive for the program's lifetime so the non-blocking file
+/// writer flushes on drop.
+fn init_tracing(verbosity: u8) -> Option<tracing_appender::non_blocking::WorkerGuard> {
+    if verbosity == 0 {
+        return None;
+    }
+
+    let level = match verbosity {
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(level));
+
+    let file_appender = tracing_appender::rolling::daily("logs", "qsis.log");
+    let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+
+    tracing_subscriber::fmt()
+        .with_env_filter(env_filter)
+        .with_writer(non_blocking)
+        .with_ansi(false)
+        .init();
 
+    Some(guard)
+}
+
+fn run_metrics(ascii: bool, format: PlotFormat) -> anyhow::Result<()> {
     let proper_time = 10.0; // years
     let proper_length = 100.0; // meters
-    
+
+    let mut log: Vec<DataPoint> = Vec::new();
     for i in 0..100 {
         let v_frac = i as f64 / 100.0;
         let v = v_frac * relativity::special::C;
         let gamma = lorentz_factor(v);
-        // let dilated_time = proper_time * gamma;
-        // let contracted_length = relativity::special::length_contraction(proper_length, v);
-    //     writeln!(
-    //     file,
-    //     "{:.2},{:.6},{:.1},{:.6},{:.1},{:.6}",
-    //     v_frac, gamma, proper_time, dilated_time, proper_length, contracted_length
-    // )?;
+        let dilated_time = proper_time * gamma;
+        let contracted_length = length_contraction(proper_length, v);
+
+        log.push(DataPoint {
+            velocity_fraction: v_frac,
+            gamma,
+            proper_time,
+            dilated_time,
+            proper_length,
+            contracted_length,
+        });
+    }
 
+    export_csv(&log)?;
+    if ascii {
+        plot_results_console(&log)?;
+    } else {
+        plot_results(&log, format)?;
     }
 
     println!("✅ Metrics written to metrics.csv");
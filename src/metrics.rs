@@ -1,5 +1,8 @@
+use std::error::Error as StdError;
+use std::fmt;
 use std::fs::File;
 use std::io::Write;
+use plotters::backend::{BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingErrorKind};
 use plotters::prelude::*;
 use anyhow::Result;
 
@@ -14,31 +17,91 @@ pub struct DataPoint {
 }
 
 pub fn export_csv(log: &[DataPoint]) -> std::io::Result<()> {
-    let mut file = File::create("metrics.csv")?;
-    writeln!(
-        file,
-        "velocity_fraction,gamma,proper_time,dilated_time,proper_length,contracted_length"
-    )?;
-    for dp in log {
+    let path = "metrics.csv";
+    let mut file = match File::create(path) {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::error!(path, error = %e, "failed to create CSV export file");
+            return Err(e);
+        }
+    };
+    let write_result = (|| {
         writeln!(
             file,
-            "{:.3},{:.6},{:.3},{:.3},{:.3},{:.3}",
-            dp.velocity_fraction,
-            dp.gamma,
-            dp.proper_time,
-            dp.dilated_time,
-            dp.proper_length,
-            dp.contracted_length,
+            "velocity_fraction,gamma,proper_time,dilated_time,proper_length,contracted_length"
         )?;
+        for dp in log {
+            writeln!(
+                file,
+                "{:.3},{:.6},{:.3},{:.3},{:.3},{:.3}",
+                dp.velocity_fraction,
+                dp.gamma,
+                dp.proper_time,
+                dp.dilated_time,
+                dp.proper_length,
+                dp.contracted_length,
+            )?;
+        }
+        Ok(())
+    })();
+
+    if let Err(e) = &write_result {
+        tracing::error!(path, error = %e, "failed writing CSV export");
+    } else {
+        tracing::info!(path, rows = log.len(), "exported metrics to CSV");
     }
-    Ok(())
+    write_result
+}
+
+/// Output format for [`plot_results`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum PlotFormat {
+    Png,
+    Svg,
+}
+
+impl fmt::Display for PlotFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlotFormat::Png => write!(f, "png"),
+            PlotFormat::Svg => write!(f, "svg"),
+        }
+    }
+}
+
+pub fn plot_results(log: &[DataPoint], format: PlotFormat) -> Result<()> {
+    let path = match format {
+        PlotFormat::Png => "plot.png",
+        PlotFormat::Svg => "plot.svg",
+    };
+    let result = match format {
+        PlotFormat::Png => {
+            let root = BitMapBackend::new(path, (800, 600)).into_drawing_area();
+            draw_chart(&root, log)
+        }
+        PlotFormat::Svg => {
+            let root = SVGBackend::new(path, (800, 600)).into_drawing_area();
+            draw_chart(&root, log)
+        }
+    };
+
+    if let Err(e) = &result {
+        tracing::error!(path, error = %e, "failed to write plot");
+    } else {
+        tracing::info!(path, rows = log.len(), "wrote plot");
+    }
+    result
 }
 
-pub fn plot_results(log: &[DataPoint]) -> Result<()> {
-    let root = BitMapBackend::new("plot.png", (800, 600)).into_drawing_area();
+/// Shared chart-drawing code, generic over any `plotters` backend so the
+/// PNG and SVG outputs don't duplicate the series/legend setup.
+fn draw_chart<DB: DrawingBackend>(root: &DrawingArea<DB, plotters::coord::Shift>, log: &[DataPoint]) -> Result<()>
+where
+    DB::ErrorType: 'static,
+{
     root.fill(&WHITE)?;
 
-    let mut chart = ChartBuilder::on(&root)
+    let mut chart = ChartBuilder::on(root)
         .caption("Relativity Effects", ("sans-serif", 20))
         .margin(10)
         .x_label_area_size(40)
@@ -73,6 +136,191 @@ pub fn plot_results(log: &[DataPoint]) -> Result<()> {
 
     chart.configure_series_labels().border_style(&BLACK).draw()?;
 
+    root.present()?;
+
+    Ok(())
+}
+
+/// What a single terminal cell in the [`ConsoleBackend`] grid is showing
+#[derive(Clone, Copy)]
+enum PixelState {
+    Empty,
+    Circle(BackendColor),
+    Text(char),
+}
+
+/// A `plotters` `DrawingBackend` that rasterizes into a grid of terminal cells
+/// instead of pixels, so charts can be drawn straight to stdout.
+struct ConsoleBackend {
+    width: u32,
+    height: u32,
+    grid: Vec<Vec<PixelState>>,
+}
+
+impl ConsoleBackend {
+    fn new(width: u32, height: u32) -> Self {
+        ConsoleBackend {
+            width,
+            height,
+            grid: vec![vec![PixelState::Empty; width as usize]; height as usize],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ConsoleBackendError;
+
+impl fmt::Display for ConsoleBackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "console backend drawing error")
+    }
+}
+
+impl StdError for ConsoleBackendError {}
+
+impl DrawingBackend for ConsoleBackend {
+    type ErrorType = ConsoleBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let mut out = String::with_capacity((self.width as usize + 1) * self.height as usize);
+        for row in &self.grid {
+            for cell in row {
+                out.push(match cell {
+                    PixelState::Empty => ' ',
+                    PixelState::Circle(color) => shade_char(*color),
+                    PixelState::Text(c) => *c,
+                });
+            }
+            out.push('\n');
+        }
+        print!("{out}");
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (x, y) = point;
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return Ok(());
+        }
+        if color.alpha > 0.0 {
+            self.grid[y as usize][x as usize] = PixelState::Circle(color);
+        }
+        Ok(())
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        _style: &S,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (x, y) = pos;
+        if y < 0 || y as u32 >= self.height {
+            return Ok(());
+        }
+        for (i, ch) in text.chars().enumerate() {
+            let cx = x + i as i32;
+            if cx >= 0 && (cx as u32) < self.width {
+                self.grid[y as usize][cx as usize] = PixelState::Text(ch);
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // Skip rect fills entirely: the default impl would blit every pixel of the
+        // full-area `root.fill(&WHITE)` background through draw_pixel, turning the
+        // whole grid into Circle(WHITE) cells instead of staying Empty/blank.
+        if fill {
+            return Ok(());
+        }
+        // Outlines (such as the legend border) are still drawn, one line per edge.
+        let (x0, y0) = upper_left;
+        let (x1, y1) = bottom_right;
+        self.draw_line((x0, y0), (x1, y0), style)?;
+        self.draw_line((x1, y0), (x1, y1), style)?;
+        self.draw_line((x1, y1), (x0, y1), style)?;
+        self.draw_line((x0, y1), (x0, y0), style)?;
+        Ok(())
+    }
+}
+
+/// Pick a character to approximate a series' color by luminance, since the
+/// terminal grid has no color channel of its own.
+fn shade_char(color: BackendColor) -> char {
+    let (r, g, b) = color.rgb;
+    let luminance = 0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64;
+    match luminance as u32 {
+        0..=60 => '@',
+        61..=120 => '#',
+        121..=170 => '*',
+        171..=210 => '+',
+        _ => '.',
+    }
+}
+
+/// Render the same three series as [`plot_results`] to stdout as ASCII art,
+/// so `qsis metrics --ascii` works headless over SSH without producing `plot.png`.
+pub fn plot_results_console(log: &[DataPoint]) -> Result<()> {
+    let root = ConsoleBackend::new(100, 40).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Relativity Effects", ("sans-serif", 10))
+        .margin(2)
+        .x_label_area_size(4)
+        .y_label_area_size(8)
+        .build_cartesian_2d(0f64..1f64, 0f64..(log.iter().map(|d| d.dilated_time).fold(0.0_f64, f64::max)))?;
+
+    chart.configure_mesh().draw()?;
+
+    chart
+        .draw_series(LineSeries::new(
+            log.iter().map(|d| (d.velocity_fraction, d.dilated_time)),
+            &BLUE,
+        ))?
+        .label("Time Dilation")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], &BLUE));
+
+    chart
+        .draw_series(LineSeries::new(
+            log.iter().map(|d| (d.velocity_fraction, d.contracted_length)),
+            &RED,
+        ))?
+        .label("Length Contraction")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], &RED));
+
+    chart
+        .draw_series(LineSeries::new(
+            log.iter().map(|d| (d.velocity_fraction, d.gamma)),
+            &GREEN,
+        ))?
+        .label("Lorentz Factor γ")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 10, y)], &GREEN));
+
+    chart.configure_series_labels().border_style(&BLACK).draw()?;
+
+    root.present()?;
+    tracing::info!(rows = log.len(), "rendered plot to console");
+
     Ok(())
 }
 
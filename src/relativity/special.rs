@@ -2,6 +2,9 @@ pub const C: f64 = 299_792_458.0;
 
 /// Lorentz factor γ = 1 / sqrt(1 - v^2/c^2)
 pub fn lorentz_factor(v: f64) -> f64 {
+    if v.abs() >= C {
+        tracing::warn!(v, "velocity at or above c; lorentz_factor will return NaN/∞");
+    }
     1.0 / (1.0 - (v * v) / (C * C)).sqrt()
 }
 
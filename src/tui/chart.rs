@@ -0,0 +1,127 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, LegendPosition, Widget},
+};
+
+use crate::metrics::DataPoint;
+
+/// Which dataset(s) to show in the chart
+#[derive(Clone, Copy)]
+pub enum ChartMode {
+    All,
+    TimeDilation,
+    LengthContraction,
+    LorentzFactor,
+}
+
+/// Renders the γ / time-dilation / length-contraction curves for a session's
+/// `log`, so the same chart can be embedded anywhere a `Rect` is available
+/// instead of being built inline inside `terminal.draw`.
+pub struct RelativityChart<'a> {
+    pub log: &'a [DataPoint],
+    pub mode: ChartMode,
+    pub legend_position: Option<LegendPosition>,
+    pub playing: bool,
+}
+
+impl Widget for RelativityChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // --- build owned data arrays so references live long enough ---
+        let gamma_data: Vec<(f64, f64)> = self.log.iter().map(|d| (d.velocity_fraction, d.gamma)).collect();
+        let time_data: Vec<(f64, f64)> = self.log.iter().map(|d| (d.velocity_fraction, d.dilated_time)).collect();
+        let length_data: Vec<(f64, f64)> = self.log.iter().map(|d| (d.velocity_fraction, d.contracted_length)).collect();
+
+        // choose datasets based on mode
+        let datasets = match self.mode {
+            ChartMode::All => vec![
+                Dataset::default()
+                    .name("γ (Lorentz)")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Yellow))
+                    .data(&gamma_data),
+                Dataset::default()
+                    .name("Time Dilation")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Cyan))
+                    .data(&time_data),
+                Dataset::default()
+                    .name("Length Contraction")
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Magenta))
+                    .data(&length_data),
+            ],
+            ChartMode::TimeDilation => vec![
+                Dataset::default()
+                    .name("Time Dilation")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Cyan))
+                    .data(&time_data),
+            ],
+            ChartMode::LengthContraction => vec![
+                Dataset::default()
+                    .name("Length Contraction")
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Magenta))
+                    .data(&length_data),
+            ],
+            ChartMode::LorentzFactor => vec![
+                Dataset::default()
+                    .name("γ (Lorentz)")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Yellow))
+                    .data(&gamma_data),
+            ],
+        };
+
+        // dynamic y-axis max (safe fallback to 1.0)
+        let y_max = {
+            let max_val = match self.mode {
+                ChartMode::All => {
+                    gamma_data.iter().map(|(_, y)| *y)
+                        .chain(time_data.iter().map(|(_, y)| *y))
+                        .chain(length_data.iter().map(|(_, y)| *y))
+                        .fold(1.0_f64, f64::max)
+                }
+                ChartMode::TimeDilation => time_data.iter().map(|(_, y)| *y).fold(1.0_f64, f64::max),
+                ChartMode::LengthContraction => length_data.iter().map(|(_, y)| *y).fold(1.0_f64, f64::max),
+                ChartMode::LorentzFactor => gamma_data.iter().map(|(_, y)| *y).fold(1.0_f64, f64::max),
+            };
+            // if max_val is <= 0, fallback to 1.0
+            if max_val <= 0.0 { 1.0 } else { max_val }
+        };
+
+        // chart widget with legend hint in title (instructions)
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(format!(
+                        "Relativity Visualization — (a:all, t:time, l:length, g:γ, ←/→:change v, space:{}, tab:legend, c:clear, q:quit)",
+                        if self.playing { "pause" } else { "play" }
+                    ))
+                    .borders(Borders::ALL),
+            )
+            .x_axis(
+                Axis::default()
+                    .title("Velocity (fraction of c)")
+                    .bounds([0.0, 1.0]),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("Value")
+                    .bounds([0.0, y_max * 1.1]), // 10% padding
+            )
+            .legend_position(self.legend_position)
+            .hidden_legend_constraints((ratatui::layout::Constraint::Ratio(1, 4), ratatui::layout::Constraint::Ratio(1, 4)));
+
+        chart.render(area, buf);
+    }
+}
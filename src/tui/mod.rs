@@ -1,35 +1,44 @@
+mod chart;
+
+use std::collections::VecDeque;
 use std::io;
+use std::time::{Duration, Instant};
 
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseEventKind},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
-    symbols,
-    text::Span,
-    widgets::{Axis, Block, Borders, Chart, Dataset, Paragraph},
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders, LegendPosition, Paragraph},
     Terminal,
 };
 
 use crate::relativity::special::{lorentz_factor, length_contraction, C};
-use crate::metrics::{DataPoint, export_csv, plot_results};
-
-/// Which dataset(s) to show in the chart
-enum ChartMode {
-    All,
-    TimeDilation,
-    LengthContraction,
-    LorentzFactor,
-}
+use crate::metrics::{DataPoint, export_csv, plot_results, PlotFormat};
+use chart::{ChartMode, RelativityChart};
+
+/// Step applied to `velocity_fraction` on each playback tick
+const PLAYBACK_STEP: f64 = 0.005;
+
+/// Rolling window size for `log`; keeps per-frame redraw cost constant during long sessions
+const MAX_LOG_POINTS: usize = 500;
+
+/// Legend placements cycled through with the Tab key; `None` hides the legend
+const LEGEND_POSITIONS: [Option<LegendPosition>; 5] = [
+    Some(LegendPosition::TopRight),
+    Some(LegendPosition::TopLeft),
+    Some(LegendPosition::BottomRight),
+    Some(LegendPosition::BottomLeft),
+    None,
+];
 
 pub fn start() -> anyhow::Result<()> {
     // Terminal setup: alternate screen + raw mode
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     crossterm::terminal::enable_raw_mode()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
@@ -38,14 +47,25 @@ pub fn start() -> anyhow::Result<()> {
     let mut velocity_fraction: f64 = 0.0;
     let proper_time: f64 = 10.0; // years
     let proper_length: f64 = 100.0; // meters
-    let mut log: Vec<DataPoint> = Vec::new();
+    let mut log: VecDeque<DataPoint> = VecDeque::new();
     let mut chart_mode = ChartMode::All;
 
+    // playback state: space toggles auto-sweep instead of stepping per keystroke
+    let mut playing = false;
+    let mut playback_direction: f64 = 1.0;
+    let tick_rate = Duration::from_millis(80);
+    let mut last_tick = Instant::now();
+    let mut legend_index: usize = 0;
+
+    // plotting area of the chart from the most recent frame, used to map mouse clicks/drags to velocity
+    let mut chart_plot_rect = Rect::default();
+
     // push initial sample so chart isn't empty
-    log.push(snapshot(velocity_fraction, proper_time, proper_length));
+    push_point(&mut log, snapshot(velocity_fraction, proper_time, proper_length));
 
     // Main loop
     loop {
+        let data_slice: &[DataPoint] = log.make_contiguous();
         terminal.draw(|f| {
             // area / layout
             // note: older ratatui used `.size()`; if you have deprecation warnings you can use `.area()` depending on your ratatui version
@@ -85,127 +105,159 @@ pub fn start() -> anyhow::Result<()> {
             f.render_widget(stat_mid, chunks[1]);
             f.render_widget(stat_right, chunks[2]);
 
-            // --- build owned data arrays so references live long enough ---
-            let gamma_data: Vec<(f64, f64)> = log.iter().map(|d| (d.velocity_fraction, d.gamma)).collect();
-            let time_data: Vec<(f64, f64)> = log.iter().map(|d| (d.velocity_fraction, d.dilated_time)).collect();
-            let length_data: Vec<(f64, f64)> = log.iter().map(|d| (d.velocity_fraction, d.contracted_length)).collect();
-
-            // choose datasets based on mode
-            let datasets = match chart_mode {
-                ChartMode::All => vec![
-                    Dataset::default()
-                        .name("γ (Lorentz)")
-                        .marker(symbols::Marker::Braille)
-                        .style(Style::default().fg(Color::Yellow))
-                        .data(&gamma_data),
-                    Dataset::default()
-                        .name("Time Dilation")
-                        .marker(symbols::Marker::Braille)
-                        .style(Style::default().fg(Color::Cyan))
-                        .data(&time_data),
-                    Dataset::default()
-                        .name("Length Contraction")
-                        .marker(symbols::Marker::Dot)
-                        .style(Style::default().fg(Color::Magenta))
-                        .data(&length_data),
-                ],
-                ChartMode::TimeDilation => vec![
-                    Dataset::default()
-                        .name("Time Dilation")
-                        .marker(symbols::Marker::Braille)
-                        .style(Style::default().fg(Color::Cyan))
-                        .data(&time_data),
-                ],
-                ChartMode::LengthContraction => vec![
-                    Dataset::default()
-                        .name("Length Contraction")
-                        .marker(symbols::Marker::Dot)
-                        .style(Style::default().fg(Color::Magenta))
-                        .data(&length_data),
-                ],
-                ChartMode::LorentzFactor => vec![
-                    Dataset::default()
-                        .name("γ (Lorentz)")
-                        .marker(symbols::Marker::Braille)
-                        .style(Style::default().fg(Color::Yellow))
-                        .data(&gamma_data),
-                ],
+            let relativity_chart = RelativityChart {
+                log: data_slice,
+                mode: chart_mode,
+                legend_position: LEGEND_POSITIONS[legend_index],
+                playing,
             };
+            f.render_widget(relativity_chart, chunks[3]);
 
-            // dynamic y-axis max (safe fallback to 1.0)
-            let y_max = {
-                let max_val = match chart_mode {
-                    ChartMode::All => {
-                        gamma_data.iter().map(|(_, y)| *y)
-                            .chain(time_data.iter().map(|(_, y)| *y))
-                            .chain(length_data.iter().map(|(_, y)| *y))
-                            .fold(1.0_f64, f64::max)
-                    }
-                    ChartMode::TimeDilation => time_data.iter().map(|(_, y)| *y).fold(1.0_f64, f64::max),
-                    ChartMode::LengthContraction => length_data.iter().map(|(_, y)| *y).fold(1.0_f64, f64::max),
-                    ChartMode::LorentzFactor => gamma_data.iter().map(|(_, y)| *y).fold(1.0_f64, f64::max),
-                };
-                // if max_val is <= 0, fallback to 1.0
-                if max_val <= 0.0 { 1.0 } else { max_val }
+            // approximate the chart's inner plotting area (inside the block border)
+            // so mouse events can be mapped to a velocity
+            chart_plot_rect = Rect {
+                x: chunks[3].x + 1,
+                y: chunks[3].y + 1,
+                width: chunks[3].width.saturating_sub(2),
+                height: chunks[3].height.saturating_sub(2),
             };
-
-            // chart widget with legend hint in title (instructions)
-            let chart = Chart::new(datasets)
-                .block(
-                    Block::default()
-                        .title("Relativity Visualization — (a:all, t:time, l:length, g:γ, ←/→:change v, q:quit)")
-                        .borders(Borders::ALL),
-                )
-                .x_axis(
-                    Axis::default()
-                        .title("Velocity (fraction of c)")
-                        .bounds([0.0, 1.0]),
-                )
-                .y_axis(
-                    Axis::default()
-                        .title("Value")
-                        .bounds([0.0, y_max * 1.1]), // 10% padding
-                );
-
-            f.render_widget(chart, chunks[3]);
         })?;
 
-        // input / events
-        if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
+        // input / events — poll only for whatever time remains until the next tick;
+        // when paused there's no tick to wait for, so poll for a full tick_rate instead
+        // of spinning at 0ms once last_tick goes stale
+        let timeout = if playing {
+            tick_rate.saturating_sub(last_tick.elapsed())
+        } else {
+            tick_rate
+        };
+        if event::poll(timeout)? {
+            match event::read()? {
+                Event::Key(key) => match key.code {
                     KeyCode::Left => {
                         velocity_fraction = (velocity_fraction - 0.01).max(0.0);
-                        log.push(snapshot(velocity_fraction, proper_time, proper_length));
+                        tracing::debug!(velocity_fraction, "velocity decreased");
+                        push_point(&mut log, snapshot(velocity_fraction, proper_time, proper_length));
                     }
                     KeyCode::Right => {
                         velocity_fraction = (velocity_fraction + 0.01).min(0.99);
-                        log.push(snapshot(velocity_fraction, proper_time, proper_length));
+                        tracing::debug!(velocity_fraction, "velocity increased");
+                        push_point(&mut log, snapshot(velocity_fraction, proper_time, proper_length));
+                    }
+                    KeyCode::Char(' ') => {
+                        playing = !playing;
+                        tracing::info!(playing, "playback toggled");
+                    }
+                    KeyCode::Tab => legend_index = (legend_index + 1) % LEGEND_POSITIONS.len(),
+                    KeyCode::Char('c') => {
+                        log.clear();
+                        tracing::info!("log buffer cleared");
+                    }
+                    KeyCode::Char('a') => {
+                        chart_mode = ChartMode::All;
+                        tracing::debug!("chart mode switched to all");
+                    }
+                    KeyCode::Char('t') => {
+                        chart_mode = ChartMode::TimeDilation;
+                        tracing::debug!("chart mode switched to time dilation");
+                    }
+                    KeyCode::Char('l') => {
+                        chart_mode = ChartMode::LengthContraction;
+                        tracing::debug!("chart mode switched to length contraction");
+                    }
+                    KeyCode::Char('g') => {
+                        chart_mode = ChartMode::LorentzFactor;
+                        tracing::debug!("chart mode switched to lorentz factor");
                     }
-                    KeyCode::Char('a') => chart_mode = ChartMode::All,
-                    KeyCode::Char('t') => chart_mode = ChartMode::TimeDilation,
-                    KeyCode::Char('l') => chart_mode = ChartMode::LengthContraction,
-                    KeyCode::Char('g') => chart_mode = ChartMode::LorentzFactor,
                     KeyCode::Char('q') | KeyCode::Esc => {
+                        tracing::info!("quit requested");
                         // clean up terminal first
                         crossterm::terminal::disable_raw_mode()?;
-                        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                        execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
                         terminal.show_cursor()?;
 
                         // export and plot using metrics helpers
-                        export_csv(&log)?;
-                        plot_results(&log)?;
+                        let data_slice: &[DataPoint] = log.make_contiguous();
+                        export_csv(data_slice)?;
+                        plot_results(data_slice, PlotFormat::Png)?;
                         break;
                     }
                     _ => {}
-                }
+                },
+                Event::Mouse(mouse_event) => match mouse_event.kind {
+                    MouseEventKind::Down(_) | MouseEventKind::Drag(_) => {
+                        if let Some(v) = velocity_from_mouse_x(mouse_event.column, chart_plot_rect) {
+                            velocity_fraction = v;
+                            tracing::debug!(velocity_fraction, "velocity scrubbed via mouse");
+                            push_point(&mut log, snapshot(velocity_fraction, proper_time, proper_length));
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
             }
         }
+
+        // fixed-tick playback: auto-sweep velocity instead of waiting on keystrokes.
+        // last_tick is reset here whenever a tick elapses regardless of `playing`, so
+        // pausing doesn't leave it stale and turn the next poll() into a busy spin.
+        if last_tick.elapsed() >= tick_rate {
+            if playing {
+                on_tick(
+                    &mut velocity_fraction,
+                    &mut playback_direction,
+                    proper_time,
+                    proper_length,
+                    &mut log,
+                );
+            }
+            last_tick = Instant::now();
+        }
     }
 
     Ok(())
 }
 
+/// advance playback by one fixed tick, bouncing `velocity_fraction` at the [0.0, 0.99] bounds
+fn on_tick(
+    velocity_fraction: &mut f64,
+    direction: &mut f64,
+    proper_time: f64,
+    proper_length: f64,
+    log: &mut VecDeque<DataPoint>,
+) {
+    let next = *velocity_fraction + PLAYBACK_STEP * *direction;
+    if next >= 0.99 {
+        *velocity_fraction = 0.99;
+        *direction = -1.0;
+    } else if next <= 0.0 {
+        *velocity_fraction = 0.0;
+        *direction = 1.0;
+    } else {
+        *velocity_fraction = next;
+    }
+    push_point(log, snapshot(*velocity_fraction, proper_time, proper_length));
+}
+
+/// map a mouse column inside `plot_rect` linearly onto the `[0.0, 0.99]` velocity range;
+/// `None` if the click/drag fell outside the chart's plotting area
+fn velocity_from_mouse_x(column: u16, plot_rect: Rect) -> Option<f64> {
+    if plot_rect.width < 2 || column < plot_rect.x || column >= plot_rect.x + plot_rect.width {
+        return None;
+    }
+    let offset = (column - plot_rect.x) as f64;
+    let fraction = offset / (plot_rect.width - 1) as f64;
+    Some((fraction * 0.99).clamp(0.0, 0.99))
+}
+
+/// push a new sample onto the rolling `log`, dropping the oldest once `MAX_LOG_POINTS` is reached
+fn push_point(log: &mut VecDeque<DataPoint>, point: DataPoint) {
+    if log.len() >= MAX_LOG_POINTS {
+        log.pop_front();
+    }
+    log.push_back(point);
+}
+
 /// create DataPoint from state
 fn snapshot(velocity_fraction: f64, proper_time: f64, proper_length: f64) -> DataPoint {
     let v = velocity_fraction * C;